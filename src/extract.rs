@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use http::{header, Request};
 use serde::de::DeserializeOwned;
-use std::{collections::HashMap, convert::Infallible, str::FromStr};
+use std::{collections::HashMap, convert::Infallible, str::FromStr, sync::Arc};
 
 #[async_trait]
 pub trait FromRequest<B>: Sized {
@@ -28,6 +28,124 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum Either<A, B> {
+    A(A),
+    B(B),
+}
+
+impl<A, B> Either<A, B> {
+    pub fn into_inner(self) -> Result<A, B> {
+        match self {
+            Either::A(a) => Ok(a),
+            Either::B(b) => Err(b),
+        }
+    }
+}
+
+// Keeps both arms' rejections around. Neither is dropped: `into_response`
+// renders both messages, since which arm "should" have matched isn't
+// knowable in general (e.g. `Either<Json<T>, Form<T>>` failing both content
+// type checks and a body parse looks the same from out here).
+pub struct EitherRejection {
+    a: (http::StatusCode, Bytes),
+    b: (http::StatusCode, Bytes),
+}
+
+impl EitherRejection {
+    async fn new(a: impl IntoResponse<Body>, b: impl IntoResponse<Body>) -> Self {
+        EitherRejection {
+            a: status_and_bytes(a).await,
+            b: status_and_bytes(b).await,
+        }
+    }
+}
+
+async fn status_and_bytes(value: impl IntoResponse<Body>) -> (http::StatusCode, Bytes) {
+    let (parts, body) = value.into_response().into_parts();
+    let bytes = hyper::body::to_bytes(body).await.unwrap_or_default();
+    (parts.status, bytes)
+}
+
+impl IntoResponse<Body> for EitherRejection {
+    fn into_response(self) -> http::Response<Body> {
+        let (a_status, a_body) = self.a;
+        let (b_status, b_body) = self.b;
+
+        let mut res = http::Response::new(Body::from(format!(
+            "{}\n{}",
+            String::from_utf8_lossy(&a_body),
+            String::from_utf8_lossy(&b_body),
+        )));
+        *res.status_mut() = b_status.max(a_status);
+        res
+    }
+}
+
+// `A` and `B` both potentially consume the request body via `take_body`, so
+// we buffer it once up front and reinsert a fresh `Body` before each
+// attempt rather than letting the first failed extractor poison the second.
+// The route's URL params extension is snapshotted and reinserted the same
+// way, since extractors like `Path<T>` also `take()` it out of the request.
+#[async_trait]
+impl<A, B> FromRequest<Body> for Either<A, B>
+where
+    A: FromRequest<Body>,
+    B: FromRequest<Body>,
+    A::Rejection: Send + 'static,
+    B::Rejection: Send + 'static,
+{
+    type Rejection = EitherRejection;
+
+    async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
+        let body = match take_body(req) {
+            Ok(body) => body,
+            Err(_) => {
+                return Err(EitherRejection::new(BodyAlreadyTaken(()), BodyAlreadyTaken(())).await)
+            }
+        };
+
+        let bytes = match hyper::body::to_bytes(body).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                let msg = err.to_string();
+                return Err(EitherRejection::new(
+                    FailedToBufferBody::from_err(msg.clone()),
+                    FailedToBufferBody::from_err(msg),
+                )
+                .await)
+            }
+        };
+
+        let url_params = req
+            .extensions()
+            .get::<Option<crate::routing::UrlParams>>()
+            .cloned();
+
+        *req.body_mut() = Body::from(bytes.clone());
+        req.extensions_mut().remove::<BodyAlreadyTakenExt>();
+        if let Some(params) = url_params.clone() {
+            req.extensions_mut().insert(params);
+        }
+
+        let a_rejection = match A::from_request(req).await {
+            Ok(a) => return Ok(Either::A(a)),
+            Err(rejection) => rejection,
+        };
+
+        *req.body_mut() = Body::from(bytes);
+        req.extensions_mut().remove::<BodyAlreadyTakenExt>();
+        if let Some(params) = url_params {
+            req.extensions_mut().insert(params);
+        }
+
+        match B::from_request(req).await {
+            Ok(b) => Ok(Either::B(b)),
+            Err(b_rejection) => Err(EitherRejection::new(a_rejection, b_rejection).await),
+        }
+    }
+}
+
 macro_rules! define_rejection {
     (
         #[status = $status:ident]
@@ -124,6 +242,50 @@ define_rejection! {
     pub struct MissingJsonContentType(());
 }
 
+const DEFAULT_JSON_LIMIT: u64 = 2 * 1024 * 1024;
+
+type JsonErrorHandler = Arc<dyn Fn(serde_json::Error) -> http::Response<Body> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct JsonConfig {
+    limit: u64,
+    content_types: Vec<String>,
+    error_handler: Option<JsonErrorHandler>,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        JsonConfig {
+            limit: DEFAULT_JSON_LIMIT,
+            content_types: Vec::new(),
+            error_handler: None,
+        }
+    }
+}
+
+impl JsonConfig {
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    // Accept an additional content type, e.g. "application/*+json", on top
+    // of the default "application/json" check. A single `*` in the pattern
+    // matches any run of characters.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_types.push(content_type.into());
+        self
+    }
+
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(serde_json::Error) -> http::Response<Body> + Send + Sync + 'static,
+    {
+        self.error_handler = Some(Arc::new(f));
+        self
+    }
+}
+
 #[async_trait]
 impl<T> FromRequest<Body> for Json<T>
 where
@@ -132,21 +294,131 @@ where
     type Rejection = BoxIntoResponse<Body>;
 
     async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
-        if has_content_type(&req, "application/json") {
+        let config = req.extensions().get::<JsonConfig>().cloned();
+
+        let content_type_ok = has_content_type(&req, "application/json")
+            || config
+                .as_ref()
+                .map(|config| {
+                    config
+                        .content_types
+                        .iter()
+                        .any(|pattern| content_type_matches(&req, pattern))
+                })
+                .unwrap_or(false);
+
+        if !content_type_ok {
+            return Err(MissingJsonContentType(()).boxed());
+        }
+
+        // No `JsonConfig` means no configured limit, so behavior stays
+        // exactly as before this limit was added: unbounded buffering.
+        let limit = config.as_ref().map(|config| config.limit).unwrap_or(u64::MAX);
+
+        if let Some(config) = &config {
+            let content_length = req
+                .headers()
+                .get(http::header::CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok()?.parse::<u64>().ok());
+
+            if content_length
+                .map(|length| length > config.limit)
+                .unwrap_or(false)
+            {
+                return Err(PayloadTooLarge(()).boxed());
+            }
+        }
+
+        let body = take_body(req).map_err(IntoResponse::boxed)?;
+
+        let bytes = read_body_with_limit(body, limit).await.map_err(|err| {
+            match err {
+                LimitedBodyError::TooLarge => PayloadTooLarge(()).boxed(),
+                LimitedBodyError::Buffer(err) => InvalidJsonBody::from_err(err).boxed(),
+            }
+        })?;
+
+        match serde_json::from_slice(&bytes) {
+            Ok(value) => Ok(Json(value)),
+            Err(err) => match config.and_then(|config| config.error_handler) {
+                Some(error_handler) => Err(error_handler(err).boxed()),
+                None => Err(InvalidJsonBody::from_err(err).boxed()),
+            },
+        }
+    }
+}
+
+enum LimitedBodyError {
+    TooLarge,
+    Buffer(tower::BoxError),
+}
+
+// Reads `body` chunk by chunk, rejecting as soon as the running total goes
+// over `limit` rather than buffering the whole thing first and checking
+// after, so a body with no `Content-Length` can't be used to force an
+// unbounded allocation.
+async fn read_body_with_limit(mut body: Body, limit: u64) -> Result<Bytes, LimitedBodyError> {
+    use futures_util::StreamExt;
+
+    let mut buf = Vec::new();
+
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|err| LimitedBodyError::Buffer(err.into()))?;
+
+        if buf.len() as u64 + chunk.len() as u64 > limit {
+            return Err(LimitedBodyError::TooLarge);
+        }
+
+        buf.extend_from_slice(&chunk);
+    }
+
+    Ok(Bytes::from(buf))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Form<T>(T);
+
+impl<T> Form<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Failed to parse the request body as a form"]
+    pub struct InvalidFormBody(BoxError);
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Expected request with `Content-Type: application/x-www-form-urlencoded`"]
+    pub struct MissingFormContentType(());
+}
+
+#[async_trait]
+impl<T> FromRequest<Body> for Form<T>
+where
+    T: DeserializeOwned,
+{
+    type Rejection = BoxIntoResponse<Body>;
+
+    async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
+        if has_content_type(&req, "application/x-www-form-urlencoded") {
             let body = take_body(req).map_err(IntoResponse::boxed)?;
 
             let bytes = hyper::body::to_bytes(body)
                 .await
-                .map_err(InvalidJsonBody::from_err)
+                .map_err(InvalidFormBody::from_err)
                 .map_err(IntoResponse::boxed)?;
 
-            let value = serde_json::from_slice(&bytes)
-                .map_err(InvalidJsonBody::from_err)
+            let value = serde_urlencoded::from_bytes(&bytes)
+                .map_err(InvalidFormBody::from_err)
                 .map_err(IntoResponse::boxed)?;
 
-            Ok(Json(value))
+            Ok(Form(value))
         } else {
-            Err(MissingJsonContentType(()).boxed())
+            Err(MissingFormContentType(()).boxed())
         }
     }
 }
@@ -167,6 +439,29 @@ fn has_content_type<B>(req: &Request<B>, expected_content_type: &str) -> bool {
     content_type.starts_with(expected_content_type)
 }
 
+fn content_type_matches<B>(req: &Request<B>, pattern: &str) -> bool {
+    let content_type = if let Some(content_type) = req.headers().get(header::CONTENT_TYPE) {
+        content_type
+    } else {
+        return false;
+    };
+
+    let content_type = if let Ok(content_type) = content_type.to_str() {
+        content_type
+    } else {
+        return false;
+    };
+
+    if let Some(idx) = pattern.find('*') {
+        let (prefix, suffix) = (&pattern[..idx], &pattern[idx + 1..]);
+        content_type.len() >= prefix.len() + suffix.len()
+            && content_type.starts_with(prefix)
+            && content_type.ends_with(suffix)
+    } else {
+        content_type == pattern
+    }
+}
+
 define_rejection! {
     #[status = INTERNAL_SERVER_ERROR]
     #[body = "Missing request extension"]
@@ -284,12 +579,15 @@ impl<const N: u64> FromRequest<Body> for BytesMaxLength<N> {
     type Rejection = BoxIntoResponse<Body>;
 
     async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
-        let content_length = req.headers().get(http::header::CONTENT_LENGTH).cloned();
-        let body = take_body(req).map_err(|reject| reject.boxed())?;
-
-        let content_length =
-            content_length.and_then(|value| value.to_str().ok()?.parse::<u64>().ok());
-
+        let content_length = req
+            .headers()
+            .get(http::header::CONTENT_LENGTH)
+            .cloned()
+            .and_then(|value| value.to_str().ok()?.parse::<u64>().ok());
+
+        // Check the length up front and only call `take_body` (which signals
+        // `100 Continue` once it runs) once we've actually decided to read
+        // the body, so oversized/unsized requests get rejected immediately.
         if let Some(length) = content_length {
             if length > N {
                 return Err(PayloadTooLarge(()).boxed());
@@ -298,6 +596,8 @@ impl<const N: u64> FromRequest<Body> for BytesMaxLength<N> {
             return Err(LengthRequired(()).boxed());
         };
 
+        let body = take_body(req).map_err(|reject| reject.boxed())?;
+
         let bytes = hyper::body::to_bytes(body)
             .await
             .map_err(|e| FailedToBufferBody::from_err(e).boxed())?;
@@ -306,6 +606,93 @@ impl<const N: u64> FromRequest<Body> for BytesMaxLength<N> {
     }
 }
 
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Expected request with `Content-Type: multipart/form-data`"]
+    pub struct MissingMultipartContentType(());
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Invalid `boundary` for `multipart/form-data` request"]
+    pub struct InvalidBoundary(());
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Error while reading multipart field"]
+    pub struct MultipartError(BoxError);
+}
+
+pub struct Multipart {
+    inner: multer::Multipart<'static>,
+}
+
+impl Multipart {
+    // The yielded `Field` borrows from the underlying stream's lifetime, not
+    // from this `&mut self` call, so it's always `'static` here since
+    // `inner` is a `multer::Multipart<'static>`.
+    pub async fn next_field(&mut self) -> Result<Option<Field<'static>>, MultipartError> {
+        let field = self
+            .inner
+            .next_field()
+            .await
+            .map_err(MultipartError::from_err)?;
+
+        Ok(field.map(|inner| Field { inner }))
+    }
+}
+
+#[async_trait]
+impl FromRequest<Body> for Multipart {
+    type Rejection = BoxIntoResponse<Body>;
+
+    async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
+        if !has_content_type(&req, "multipart/form-data") {
+            return Err(MissingMultipartContentType(()).boxed());
+        }
+
+        let boundary = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|content_type| multer::parse_boundary(content_type).ok())
+            .ok_or_else(|| InvalidBoundary(()).boxed())?;
+
+        let body = take_body(req).map_err(IntoResponse::boxed)?;
+
+        Ok(Multipart {
+            inner: multer::Multipart::new(body, boundary),
+        })
+    }
+}
+
+pub struct Field<'a> {
+    inner: multer::Field<'a>,
+}
+
+impl<'a> Field<'a> {
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    pub fn file_name(&self) -> Option<&str> {
+        self.inner.file_name()
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.inner.content_type().map(|mime| mime.as_ref())
+    }
+
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, MultipartError> {
+        self.inner.chunk().await.map_err(MultipartError::from_err)
+    }
+
+    pub async fn bytes(self) -> Result<Bytes, MultipartError> {
+        self.inner.bytes().await.map_err(MultipartError::from_err)
+    }
+}
+
 define_rejection! {
     #[status = INTERNAL_SERVER_ERROR]
     #[body = "No url params found for matched route. This is a bug in tower-web. Please open an issue"]
@@ -470,18 +857,495 @@ impl<T1, T2, T3, T4, T5, T6> UrlParams<(T1, T2, T3, T4, T5, T6)> {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Path<T>(T);
+
+impl<T> Path<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Failed to deserialize path params"]
+    pub struct InvalidPathParams(BoxError);
+}
+
+#[async_trait]
+impl<T> FromRequest<Body> for Path<T>
+where
+    T: DeserializeOwned,
+{
+    type Rejection = BoxIntoResponse<Body>;
+
+    async fn from_request(req: &mut Request<Body>) -> Result<Self, Self::Rejection> {
+        let params = if let Some(params) = req
+            .extensions_mut()
+            .get_mut::<Option<crate::routing::UrlParams>>()
+        {
+            params.take().expect("params already taken").0
+        } else {
+            return Err(MissingRouteParams(()).boxed());
+        };
+
+        T::deserialize(path_de::PathDeserializer::new(&params))
+            .map(Path)
+            .map_err(InvalidPathParams::from_err)
+            .map_err(IntoResponse::boxed)
+    }
+}
+
+// A minimal serde `Deserializer` that turns the `(name, value)` pairs
+// captured from a route's URL params into a struct, map, tuple or sequence
+// (or a unit-variant enum from exactly one param), so `Path<T>` doesn't
+// force callers through `UrlParamsMap`/`UrlParams` string lookups. A bare
+// primitive or string `T` is not supported: it is handed a map and fails.
+// Each value is re-parsed through the primitive-specific `deserialize_*`
+// call the target type's `Deserialize` impl makes, so e.g. a `u32` field
+// is parsed with `str::parse::<u32>` rather than handed over as a string.
+mod path_de {
+    use serde::de::{
+        self, DeserializeSeed, Deserializer, EnumAccess, Error as _, MapAccess, SeqAccess,
+        VariantAccess, Visitor,
+    };
+    use std::fmt;
+
+    #[derive(Debug)]
+    pub(super) struct PathDeserializationError {
+        key: Option<String>,
+        message: String,
+    }
+
+    impl PathDeserializationError {
+        fn with_key(mut self, key: &str) -> Self {
+            if self.key.is_none() {
+                self.key = Some(key.to_owned());
+            }
+            self
+        }
+    }
+
+    impl fmt::Display for PathDeserializationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.key {
+                Some(key) => write!(f, "param `{}`: {}", key, self.message),
+                None => f.write_str(&self.message),
+            }
+        }
+    }
+
+    impl std::error::Error for PathDeserializationError {}
+
+    impl de::Error for PathDeserializationError {
+        fn custom<T>(msg: T) -> Self
+        where
+            T: fmt::Display,
+        {
+            PathDeserializationError {
+                key: None,
+                message: msg.to_string(),
+            }
+        }
+    }
+
+    pub(super) struct PathDeserializer<'de> {
+        params: &'de [(String, String)],
+    }
+
+    impl<'de> PathDeserializer<'de> {
+        pub(super) fn new(params: &'de [(String, String)]) -> Self {
+            PathDeserializer { params }
+        }
+    }
+
+    impl<'de> Deserializer<'de> for PathDeserializer<'de> {
+        type Error = PathDeserializationError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_map(visitor)
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_unit()
+        }
+
+        fn deserialize_unit_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_unit(visitor)
+        }
+
+        fn deserialize_newtype_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(PathSeqAccess {
+                params: self.params.iter(),
+            })
+        }
+
+        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_tuple_struct<V>(
+            self,
+            _name: &'static str,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_map(PathMapAccess {
+                params: self.params.iter(),
+                value: None,
+            })
+        }
+
+        fn deserialize_struct<V>(
+            self,
+            _name: &'static str,
+            _fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_map(visitor)
+        }
+
+        fn deserialize_enum<V>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if let [(key, value)] = self.params {
+                visitor.visit_enum(PathEnumAccess { key, value })
+            } else {
+                Err(PathDeserializationError::custom(
+                    "can only deserialize an enum from exactly one path param",
+                ))
+            }
+        }
+
+        fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_unit()
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf identifier
+        }
+    }
+
+    struct PathSeqAccess<'de> {
+        params: std::slice::Iter<'de, (String, String)>,
+    }
+
+    impl<'de> SeqAccess<'de> for PathSeqAccess<'de> {
+        type Error = PathDeserializationError;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            match self.params.next() {
+                Some((key, value)) => seed
+                    .deserialize(PathValueDeserializer { value })
+                    .map(Some)
+                    .map_err(|err| err.with_key(key)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    struct PathMapAccess<'de> {
+        params: std::slice::Iter<'de, (String, String)>,
+        value: Option<&'de str>,
+    }
+
+    impl<'de> MapAccess<'de> for PathMapAccess<'de> {
+        type Error = PathDeserializationError;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            match self.params.next() {
+                Some((key, value)) => {
+                    self.value = Some(value);
+                    seed.deserialize(PathValueDeserializer { value: key })
+                        .map(Some)
+                }
+                None => Ok(None),
+            }
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let value = self
+                .value
+                .take()
+                .expect("next_value_seed called before next_key_seed");
+            seed.deserialize(PathValueDeserializer { value })
+        }
+    }
+
+    struct PathEnumAccess<'de> {
+        key: &'de str,
+        value: &'de str,
+    }
+
+    impl<'de> EnumAccess<'de> for PathEnumAccess<'de> {
+        type Error = PathDeserializationError;
+        type Variant = PathVariantAccess;
+
+        fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let value = seed
+                .deserialize(PathValueDeserializer { value: self.value })
+                .map_err(|err| err.with_key(self.key))?;
+            Ok((value, PathVariantAccess))
+        }
+    }
+
+    struct PathVariantAccess;
+
+    impl<'de> VariantAccess<'de> for PathVariantAccess {
+        type Error = PathDeserializationError;
+
+        fn unit_variant(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn newtype_variant_seed<T>(self, _seed: T) -> Result<T::Value, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            Err(de::Error::custom(
+                "newtype enum variants are not supported for path params",
+            ))
+        }
+
+        fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            Err(de::Error::custom(
+                "tuple enum variants are not supported for path params",
+            ))
+        }
+
+        fn struct_variant<V>(
+            self,
+            _fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            Err(de::Error::custom(
+                "struct enum variants are not supported for path params",
+            ))
+        }
+    }
+
+    // Deserializes a single path param's string value, parsing it through
+    // whichever primitive `deserialize_*` method the target field's
+    // `Deserialize` impl calls (e.g. `deserialize_u32` parses with
+    // `str::parse::<u32>`) instead of always handing back a string.
+    struct PathValueDeserializer<'de> {
+        value: &'de str,
+    }
+
+    macro_rules! parse_value {
+        ($method:ident => $visit:ident, $ty:ty) => {
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let parsed = self.value.parse::<$ty>().map_err(|_| {
+                    PathDeserializationError::custom(format!(
+                        "can not parse `{}` as `{}`",
+                        self.value,
+                        stringify!($ty)
+                    ))
+                })?;
+                visitor.$visit(parsed)
+            }
+        };
+    }
+
+    impl<'de> Deserializer<'de> for PathValueDeserializer<'de> {
+        type Error = PathDeserializationError;
+
+        parse_value!(deserialize_bool => visit_bool, bool);
+        parse_value!(deserialize_i8 => visit_i8, i8);
+        parse_value!(deserialize_i16 => visit_i16, i16);
+        parse_value!(deserialize_i32 => visit_i32, i32);
+        parse_value!(deserialize_i64 => visit_i64, i64);
+        parse_value!(deserialize_u8 => visit_u8, u8);
+        parse_value!(deserialize_u16 => visit_u16, u16);
+        parse_value!(deserialize_u32 => visit_u32, u32);
+        parse_value!(deserialize_u64 => visit_u64, u64);
+        parse_value!(deserialize_f32 => visit_f32, f32);
+        parse_value!(deserialize_f64 => visit_f64, f64);
+        parse_value!(deserialize_char => visit_char, char);
+
+        fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_borrowed_str(self.value)
+        }
+
+        fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_string(self.value.to_owned())
+        }
+
+        fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_borrowed_bytes(self.value.as_bytes())
+        }
+
+        fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_byte_buf(self.value.as_bytes().to_vec())
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_str(visitor)
+        }
+
+        fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_str(visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            unit unit_struct seq tuple tuple_struct map struct enum ignored_any newtype_struct
+        }
+    }
+}
+
 define_rejection! {
     #[status = INTERNAL_SERVER_ERROR]
     #[body = "Cannot have two request body extractors for a single handler"]
     pub struct BodyAlreadyTaken(());
 }
 
-fn take_body(req: &mut Request<Body>) -> Result<Body, BodyAlreadyTaken> {
-    struct BodyAlreadyTakenExt;
+// Hook the server inserts into the request extensions so body-consuming
+// extractors can emit an interim 100 Continue once they're actually going
+// to read the body.
+pub trait SendContinue: Send + Sync {
+    fn send_continue(&self);
+}
 
+fn expects_continue<B>(req: &Request<B>) -> bool {
+    req.headers()
+        .get(http::header::EXPECT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.eq_ignore_ascii_case("100-continue"))
+        .unwrap_or(false)
+}
+
+struct ContinueAlreadySent;
+
+// `take_body` can legitimately run more than once for a single incoming
+// request (e.g. `Either` clears `BodyAlreadyTakenExt` and retries with a
+// fresh `Body` for its second arm), so guard on a separate extension flag
+// to make sure the 100 Continue is only ever sent once per request.
+fn send_continue_if_expected(req: &mut Request<Body>) {
+    if !expects_continue(req) {
+        return;
+    }
+
+    if req.extensions_mut().insert(ContinueAlreadySent).is_some() {
+        return;
+    }
+
+    if let Some(responder) = req.extensions().get::<Arc<dyn SendContinue>>() {
+        responder.send_continue();
+    }
+}
+
+struct BodyAlreadyTakenExt;
+
+fn take_body(req: &mut Request<Body>) -> Result<Body, BodyAlreadyTaken> {
     if req.extensions_mut().insert(BodyAlreadyTakenExt).is_some() {
         Err(BodyAlreadyTaken(()))
     } else {
+        send_continue_if_expected(req);
         let body = std::mem::take(req.body_mut());
         Ok(body)
     }